@@ -0,0 +1,94 @@
+/*
+ * Various types to support iteration.
+ */
+
+use super::super::pyobject::{
+    AttributeProtocol, PyContext, PyFuncArgs, PyObject, PyObjectKind, PyObjectRef, PyResult,
+};
+use super::super::vm::VirtualMachine;
+use std::cell::Cell;
+
+pub struct PyIteratorValue {
+    pub position: Cell<usize>,
+    pub iterated_obj: PyObjectRef,
+}
+
+pub fn new_iterator(vm: &mut VirtualMachine, iterated_obj: PyObjectRef) -> PyObjectRef {
+    PyObject::new(
+        PyObjectKind::Iterator {
+            position: Cell::new(0),
+            iterated_obj,
+        },
+        vm.ctx.iter_type(),
+    )
+}
+
+fn elements_to_iterate(vm: &mut VirtualMachine, obj: &PyObjectRef) -> PyResult<Vec<PyObjectRef>> {
+    match &obj.borrow().kind {
+        PyObjectKind::List { elements } => Ok(elements.to_vec()),
+        _ => Err(vm.new_type_error(format!("{:?} is not iterable", obj))),
+    }
+}
+
+pub fn get_iter(vm: &mut VirtualMachine, iter_target: &PyObjectRef) -> PyResult {
+    elements_to_iterate(vm, iter_target)?;
+    Ok(new_iterator(vm, iter_target.clone()))
+}
+
+pub fn get_next(vm: &mut VirtualMachine, iter_obj: &PyObjectRef) -> PyResult {
+    let iterated_obj = if let PyObjectKind::Iterator { iterated_obj, .. } = &iter_obj.borrow().kind
+    {
+        iterated_obj.clone()
+    } else {
+        return Err(vm.new_type_error("Not an iterator".to_string()));
+    };
+
+    let elements = elements_to_iterate(vm, &iterated_obj)?;
+    let position = if let PyObjectKind::Iterator { position, .. } = &iter_obj.borrow().kind {
+        position.get()
+    } else {
+        unreachable!()
+    };
+
+    if position >= elements.len() {
+        return Err(vm.new_stop_iteration());
+    }
+
+    if let PyObjectKind::Iterator { position, .. } = &iter_obj.borrow().kind {
+        position.set(position.get() + 1);
+    }
+    Ok(elements[position].clone())
+}
+
+pub fn get_all(vm: &mut VirtualMachine, iter_obj: &PyObjectRef) -> PyResult<Vec<PyObjectRef>> {
+    let mut elements = vec![];
+    loop {
+        match get_next(vm, iter_obj) {
+            Ok(element) => elements.push(element),
+            Err(err) => {
+                if vm.is_stop_iteration(&err) {
+                    break;
+                } else {
+                    return Err(err);
+                }
+            }
+        }
+    }
+    Ok(elements)
+}
+
+fn iter_iter(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(vm, args, required = [(iter_obj, Some(vm.ctx.iter_type()))]);
+    Ok(iter_obj.clone())
+}
+
+fn iter_next(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(vm, args, required = [(iter_obj, Some(vm.ctx.iter_type()))]);
+    get_next(vm, iter_obj)
+}
+
+pub fn init(context: &PyContext) {
+    let ref iter_type = context.iter_type;
+    iter_type.set_attr("__iter__", context.new_rustfunc(iter_iter));
+    iter_type.set_attr("__next__", context.new_rustfunc(iter_next));
+}
@@ -6,11 +6,12 @@ use super::super::vm::VirtualMachine;
 use super::objbool;
 use super::objint;
 use super::objiter;
-use super::objsequence::{get_item, seq_equal, PySliceableSequence};
+use super::objsequence::{get_item, seq_cmp, seq_equal, PySliceableSequence};
 use super::objstr;
 use super::objtype;
 use num_bigint::ToBigInt;
 use num_traits::ToPrimitive;
+use std::cmp::Ordering;
 
 // set_item:
 pub fn set_item(
@@ -19,16 +20,129 @@ pub fn set_item(
     idx: PyObjectRef,
     obj: PyObjectRef,
 ) -> PyResult {
+    let is_slice = match &idx.borrow().kind {
+        PyObjectKind::Slice { .. } => true,
+        _ => false,
+    };
     if objtype::isinstance(&idx, &vm.ctx.int_type()) {
         let value = objint::get_value(&idx).to_i32().unwrap();
+        if value < -(l.len() as i32) || value >= l.len() as i32 {
+            return Err(vm.new_index_error("list assignment index out of range".to_string()));
+        }
         let pos_index = l.get_pos(value);
         l[pos_index] = obj;
         Ok(vm.get_none())
+    } else if is_slice {
+        set_slice_item(vm, l, &idx, obj)
+    } else {
+        Err(vm.new_type_error(format!(
+            "indexing type {:?} with index {:?} is not supported (yet?)",
+            l, idx
+        )))
+    }
+}
+
+fn slice_indices(
+    len: usize,
+    start: &Option<num_bigint::BigInt>,
+    stop: &Option<num_bigint::BigInt>,
+    step: i32,
+) -> Vec<usize> {
+    let len_i = len as i32;
+    let (default_start, default_stop) = if step > 0 { (0, len_i) } else { (len_i - 1, -1) };
+    let lo = if step > 0 { 0 } else { -1 };
+    let hi = if step > 0 { len_i } else { len_i - 1 };
+    let normalize = |v: i32| -> i32 {
+        let v = if v < 0 { v + len_i } else { v };
+        v.max(lo).min(hi)
+    };
+
+    let start_idx = start
+        .as_ref()
+        .map(|v| normalize(v.to_i32().unwrap()))
+        .unwrap_or(default_start);
+    let stop_idx = stop
+        .as_ref()
+        .map(|v| normalize(v.to_i32().unwrap()))
+        .unwrap_or(default_stop);
+
+    let mut indices = vec![];
+    let mut i = start_idx;
+    if step > 0 {
+        while i < stop_idx {
+            indices.push(i as usize);
+            i += step;
+        }
+    } else {
+        while i > stop_idx {
+            indices.push(i as usize);
+            i += step;
+        }
+    }
+    indices
+}
+
+fn set_slice_item(
+    vm: &mut VirtualMachine,
+    l: &mut Vec<PyObjectRef>,
+    slice: &PyObjectRef,
+    obj: PyObjectRef,
+) -> PyResult {
+    let (start, stop, step) = match &slice.borrow().kind {
+        PyObjectKind::Slice { start, stop, step } => (start.clone(), stop.clone(), step.clone()),
+        _ => unreachable!(),
+    };
+    let step = step.map(|s| s.to_i32().unwrap()).unwrap_or(1);
+    let items = vm.extract_elements(&obj)?;
+
+    if step == 1 {
+        let range = l.get_slice_range(&start, &stop);
+        l.splice(range, items);
+        Ok(vm.get_none())
+    } else {
+        let indices = slice_indices(l.len(), &start, &stop, step);
+        if indices.len() != items.len() {
+            return Err(vm.new_value_error(format!(
+                "attempt to assign sequence of size {} to extended slice of size {}",
+                items.len(),
+                indices.len()
+            )));
+        }
+        for (i, item) in indices.into_iter().zip(items.into_iter()) {
+            l[i] = item;
+        }
+        Ok(vm.get_none())
+    }
+}
+
+pub fn del_item(vm: &mut VirtualMachine, l: &mut Vec<PyObjectRef>, idx: PyObjectRef) -> PyResult {
+    let slice_fields = match &idx.borrow().kind {
+        PyObjectKind::Slice { start, stop, step } => Some((start.clone(), stop.clone(), step.clone())),
+        _ => None,
+    };
+    if objtype::isinstance(&idx, &vm.ctx.int_type()) {
+        let value = objint::get_value(&idx).to_i32().unwrap();
+        if value < -(l.len() as i32) || value >= l.len() as i32 {
+            return Err(vm.new_index_error("list assignment index out of range".to_string()));
+        }
+        let pos_index = l.get_pos(value);
+        l.remove(pos_index);
+        Ok(vm.get_none())
+    } else if let Some((start, stop, step)) = slice_fields {
+        let step = step.map(|s| s.to_i32().unwrap()).unwrap_or(1);
+        let mut indices = slice_indices(l.len(), &start, &stop, step);
+        // Remove from the back first so earlier removals don't shift the
+        // positions of indices still waiting to be removed.
+        indices.sort_unstable();
+        for i in indices.into_iter().rev() {
+            l.remove(i);
+        }
+        Ok(vm.get_none())
     } else {
-        panic!(
-            "TypeError: indexing type {:?} with index {:?} is not supported (yet?)",
+        Err(vm.new_type_error(format!(
+            "indexing type {:?} with index {:?} is not supported (yet?)",
             l, idx
-        )
+        )))
     }
 }
 
@@ -83,6 +197,38 @@ fn list_eq(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     Ok(vm.ctx.new_bool(result))
 }
 
+fn list_cmp(vm: &mut VirtualMachine, args: PyFuncArgs, op: fn(Ordering) -> bool) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [(zelf, Some(vm.ctx.list_type())), (other, None)]
+    );
+
+    if !objtype::isinstance(other, &vm.ctx.list_type()) {
+        return Err(vm.new_type_error(format!("Cannot compare list and {:?}", other)));
+    }
+    let zelf = get_elements(zelf);
+    let other = get_elements(other);
+    let ordering = seq_cmp(vm, &zelf, &other)?;
+    Ok(vm.ctx.new_bool(op(ordering)))
+}
+
+fn list_lt(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    list_cmp(vm, args, |ord| ord == Ordering::Less)
+}
+
+fn list_le(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    list_cmp(vm, args, |ord| ord != Ordering::Greater)
+}
+
+fn list_gt(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    list_cmp(vm, args, |ord| ord == Ordering::Greater)
+}
+
+fn list_ge(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    list_cmp(vm, args, |ord| ord != Ordering::Less)
+}
+
 fn list_add(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     arg_check!(
         vm,
@@ -100,6 +246,55 @@ fn list_add(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     }
 }
 
+fn get_repeat_count(vm: &mut VirtualMachine, counted_obj: &PyObjectRef) -> PyResult<i32> {
+    if objtype::isinstance(counted_obj, &vm.ctx.int_type()) {
+        Ok(objint::get_value(counted_obj).to_i32().unwrap())
+    } else {
+        Err(vm.new_type_error(format!("Cannot multiply list by {:?}", counted_obj)))
+    }
+}
+
+fn list_mul(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [(list, Some(vm.ctx.list_type())), (counted, None)]
+    );
+
+    let counted = get_repeat_count(vm, counted)?;
+    let elements = get_elements(list);
+    let new_elements = if counted <= 0 {
+        vec![]
+    } else {
+        elements.repeat(counted as usize)
+    };
+    Ok(vm.ctx.new_list(new_elements))
+}
+
+fn list_imul(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [(list, Some(vm.ctx.list_type())), (counted, None)]
+    );
+
+    let counted = get_repeat_count(vm, counted)?;
+    let mut list_obj = list.borrow_mut();
+    if let PyObjectKind::List { ref mut elements } = list_obj.kind {
+        if counted <= 0 {
+            elements.clear();
+        } else {
+            let original = elements.clone();
+            for _ in 1..counted {
+                elements.extend(original.iter().cloned());
+            }
+        }
+        Ok(vm.get_none())
+    } else {
+        Err(vm.new_type_error("list.__imul__ is called with no list".to_string()))
+    }
+}
+
 fn list_repr(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     arg_check!(vm, args, required = [(o, Some(vm.ctx.list_type()))]);
 
@@ -177,6 +372,196 @@ fn list_reverse(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     }
 }
 
+fn list_pop(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    trace!("list.pop called with: {:?}", args);
+    arg_check!(
+        vm,
+        args,
+        required = [(list, Some(vm.ctx.list_type()))],
+        optional = [(i, Some(vm.ctx.int_type()))]
+    );
+    let mut list_obj = list.borrow_mut();
+    if let PyObjectKind::List { ref mut elements } = list_obj.kind {
+        if elements.is_empty() {
+            return Err(vm.new_index_error("pop from empty list".to_string()));
+        }
+        let index = match i {
+            Some(value) => {
+                let raw_index = objint::get_value(value).to_i32().unwrap();
+                if raw_index < -(elements.len() as i32) || raw_index >= elements.len() as i32 {
+                    return Err(vm.new_index_error("pop index out of range".to_string()));
+                }
+                elements.get_pos(raw_index)
+            }
+            None => elements.len() - 1,
+        };
+        Ok(elements.remove(index))
+    } else {
+        Err(vm.new_type_error("list.pop is called with no list".to_string()))
+    }
+}
+
+fn list_insert(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [
+            (list, Some(vm.ctx.list_type())),
+            (insert_position, Some(vm.ctx.int_type())),
+            (x, None)
+        ]
+    );
+    let mut list_obj = list.borrow_mut();
+    if let PyObjectKind::List { ref mut elements } = list_obj.kind {
+        let mut insert_position = objint::get_value(insert_position).to_i32().unwrap();
+        if insert_position < 0 {
+            insert_position = (elements.len() as i32 + insert_position).max(0);
+        }
+        let insert_position = (insert_position as usize).min(elements.len());
+        elements.insert(insert_position, x.clone());
+        Ok(vm.get_none())
+    } else {
+        Err(vm.new_type_error("list.insert is called with no list".to_string()))
+    }
+}
+
+fn list_remove(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [(list, Some(vm.ctx.list_type())), (needle, None)]
+    );
+    let elements = get_elements(list);
+    for (index, element) in elements.iter().enumerate() {
+        let value = vm.call_method(needle, "__eq__", vec![element.clone()])?;
+        if objbool::get_value(&value) {
+            let mut list_obj = list.borrow_mut();
+            if let PyObjectKind::List { ref mut elements } = list_obj.kind {
+                elements.remove(index);
+                return Ok(vm.get_none());
+            }
+        }
+    }
+    Err(vm.new_value_error("list.remove(x): x not in list".to_string()))
+}
+
+fn list_index(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [(list, Some(vm.ctx.list_type())), (needle, None)],
+        optional = [
+            (start, Some(vm.ctx.int_type())),
+            (stop, Some(vm.ctx.int_type()))
+        ]
+    );
+    let elements = get_elements(list);
+    let start = start
+        .map(|i| elements.get_pos(objint::get_value(i).to_i32().unwrap()))
+        .unwrap_or(0);
+    let stop = stop
+        .map(|i| elements.get_pos(objint::get_value(i).to_i32().unwrap()))
+        .unwrap_or_else(|| elements.len());
+
+    for (index, element) in elements.iter().enumerate() {
+        if index < start || index >= stop {
+            continue;
+        }
+        let value = vm.call_method(needle, "__eq__", vec![element.clone()])?;
+        if objbool::get_value(&value) {
+            return Ok(vm.context().new_int(index.to_bigint().unwrap()));
+        }
+    }
+    Err(vm.new_value_error(format!("'{:?}' is not in list", needle)))
+}
+
+fn list_count(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [(list, Some(vm.ctx.list_type())), (needle, None)]
+    );
+    let mut count = 0;
+    for element in get_elements(list).iter() {
+        let value = vm.call_method(needle, "__eq__", vec![element.clone()])?;
+        if objbool::get_value(&value) {
+            count += 1;
+        }
+    }
+    Ok(vm.context().new_int(count.to_bigint().unwrap()))
+}
+
+fn list_copy(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(vm, args, required = [(list, Some(vm.ctx.list_type()))]);
+    Ok(vm.ctx.new_list(get_elements(list)))
+}
+
+fn merge_sort(
+    vm: &mut VirtualMachine,
+    pairs: Vec<(PyObjectRef, PyObjectRef)>,
+) -> PyResult<Vec<(PyObjectRef, PyObjectRef)>> {
+    if pairs.len() <= 1 {
+        return Ok(pairs);
+    }
+    let mid = pairs.len() / 2;
+    let mut left = pairs;
+    let right = left.split_off(mid);
+    let left = merge_sort(vm, left)?;
+    let right = merge_sort(vm, right)?;
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    loop {
+        match (left.peek(), right.peek()) {
+            (Some((lk, _)), Some((rk, _))) => {
+                let rk_lt_lk = vm.call_method(rk, "__lt__", vec![lk.clone()])?;
+                if objbool::get_value(&rk_lt_lk) {
+                    merged.push(right.next().unwrap());
+                } else {
+                    merged.push(left.next().unwrap());
+                }
+            }
+            (Some(_), None) => merged.push(left.next().unwrap()),
+            (None, Some(_)) => merged.push(right.next().unwrap()),
+            (None, None) => break,
+        }
+    }
+    Ok(merged)
+}
+
+fn list_sort(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(vm, args, required = [(list, Some(vm.ctx.list_type()))]);
+    let key_func = args.get_optional_kwarg("key");
+    let reverse = match args.get_optional_kwarg("reverse") {
+        Some(value) => objbool::get_value(&value),
+        None => false,
+    };
+
+    let elements = get_elements(list);
+    let mut pairs = Vec::with_capacity(elements.len());
+    for element in elements {
+        let key = match &key_func {
+            Some(f) => vm.invoke(f.clone(), PyFuncArgs::new(vec![element.clone()], vec![]))?,
+            None => element.clone(),
+        };
+        pairs.push((key, element));
+    }
+
+    let mut pairs = merge_sort(vm, pairs)?;
+    if reverse {
+        pairs.reverse();
+    }
+
+    let mut list_obj = list.borrow_mut();
+    if let PyObjectKind::List { ref mut elements } = list_obj.kind {
+        *elements = pairs.into_iter().map(|(_, element)| element).collect();
+        Ok(vm.get_none())
+    } else {
+        Err(vm.new_type_error("list.sort is called with no list".to_string()))
+    }
+}
+
 fn list_contains(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     trace!("list.contains called with: {:?}", args);
     arg_check!(
@@ -208,17 +593,71 @@ fn list_getitem(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     get_item(vm, list, &get_elements(list), needle.clone())
 }
 
+fn list_iter(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(vm, args, required = [(list, Some(vm.ctx.list_type()))]);
+    objiter::get_iter(vm, list)
+}
+
+fn list_setitem(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [
+            (list, Some(vm.ctx.list_type())),
+            (needle, None),
+            (value, None)
+        ]
+    );
+    let mut list_obj = list.borrow_mut();
+    if let PyObjectKind::List { ref mut elements } = list_obj.kind {
+        set_item(vm, elements, needle.clone(), value.clone())
+    } else {
+        Err(vm.new_type_error("list.__setitem__ is called with no list".to_string()))
+    }
+}
+
+fn list_delitem(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_check!(
+        vm,
+        args,
+        required = [(list, Some(vm.ctx.list_type())), (needle, None)]
+    );
+    let mut list_obj = list.borrow_mut();
+    if let PyObjectKind::List { ref mut elements } = list_obj.kind {
+        del_item(vm, elements, needle.clone())
+    } else {
+        Err(vm.new_type_error("list.__delitem__ is called with no list".to_string()))
+    }
+}
+
 pub fn init(context: &PyContext) {
     let ref list_type = context.list_type;
     list_type.set_attr("__add__", context.new_rustfunc(list_add));
     list_type.set_attr("__contains__", context.new_rustfunc(list_contains));
+    list_type.set_attr("__delitem__", context.new_rustfunc(list_delitem));
     list_type.set_attr("__eq__", context.new_rustfunc(list_eq));
+    list_type.set_attr("__ge__", context.new_rustfunc(list_ge));
     list_type.set_attr("__getitem__", context.new_rustfunc(list_getitem));
+    list_type.set_attr("__gt__", context.new_rustfunc(list_gt));
+    list_type.set_attr("__imul__", context.new_rustfunc(list_imul));
+    list_type.set_attr("__iter__", context.new_rustfunc(list_iter));
+    list_type.set_attr("__le__", context.new_rustfunc(list_le));
     list_type.set_attr("__len__", context.new_rustfunc(list_len));
+    list_type.set_attr("__lt__", context.new_rustfunc(list_lt));
+    list_type.set_attr("__mul__", context.new_rustfunc(list_mul));
     list_type.set_attr("__new__", context.new_rustfunc(list_new));
     list_type.set_attr("__repr__", context.new_rustfunc(list_repr));
+    list_type.set_attr("__rmul__", context.new_rustfunc(list_mul));
+    list_type.set_attr("__setitem__", context.new_rustfunc(list_setitem));
     list_type.set_attr("append", context.new_rustfunc(list_append));
     list_type.set_attr("clear", context.new_rustfunc(list_clear));
+    list_type.set_attr("copy", context.new_rustfunc(list_copy));
+    list_type.set_attr("count", context.new_rustfunc(list_count));
     list_type.set_attr("extend", context.new_rustfunc(list_extend));
+    list_type.set_attr("index", context.new_rustfunc(list_index));
+    list_type.set_attr("insert", context.new_rustfunc(list_insert));
+    list_type.set_attr("pop", context.new_rustfunc(list_pop));
+    list_type.set_attr("remove", context.new_rustfunc(list_remove));
     list_type.set_attr("reverse", context.new_rustfunc(list_reverse));
+    list_type.set_attr("sort", context.new_rustfunc(list_sort));
 }
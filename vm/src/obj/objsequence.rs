@@ -0,0 +1,140 @@
+use super::super::pyobject::{PyObjectKind, PyObjectRef, PyResult};
+use super::super::vm::VirtualMachine;
+use num_bigint::{BigInt, ToBigInt};
+use num_traits::ToPrimitive;
+use std::ops::Range;
+
+pub trait PySliceableSequence {
+    fn get_pos(&self, p: i32) -> usize;
+    fn get_slice_range(&self, start: &Option<BigInt>, stop: &Option<BigInt>) -> Range<usize>;
+    fn get_slice_items(
+        &self,
+        vm: &mut VirtualMachine,
+        slice: &PyObjectRef,
+    ) -> Result<Vec<PyObjectRef>, PyObjectRef>;
+}
+
+impl PySliceableSequence for Vec<PyObjectRef> {
+    fn get_pos(&self, p: i32) -> usize {
+        if p < 0 {
+            if -p as usize > self.len() {
+                0
+            } else {
+                self.len() - ((-p) as usize)
+            }
+        } else if p as usize > self.len() {
+            self.len()
+        } else {
+            p as usize
+        }
+    }
+
+    fn get_slice_range(&self, start: &Option<BigInt>, stop: &Option<BigInt>) -> Range<usize> {
+        let start = match start {
+            Some(v) => self.get_pos(v.to_i32().unwrap()),
+            None => 0,
+        };
+        let stop = match stop {
+            Some(v) => self.get_pos(v.to_i32().unwrap()),
+            None => self.len(),
+        };
+        start..if stop < start { start } else { stop }
+    }
+
+    fn get_slice_items(
+        &self,
+        vm: &mut VirtualMachine,
+        slice: &PyObjectRef,
+    ) -> Result<Vec<PyObjectRef>, PyObjectRef> {
+        if let PyObjectKind::Slice { start, stop, step } = &slice.borrow().kind {
+            let step = step.clone().unwrap_or_else(|| 1.to_bigint().unwrap());
+            if step == 1.to_bigint().unwrap() {
+                let range = self.get_slice_range(start, stop);
+                Ok(self[range].to_vec())
+            } else {
+                let range = self.get_slice_range(start, stop);
+                let step = step.to_i32().unwrap();
+                if step > 0 {
+                    Ok(self[range].iter().step_by(step as usize).cloned().collect())
+                } else {
+                    let mut elements: Vec<PyObjectRef> = self[range].to_vec();
+                    elements.reverse();
+                    Ok(elements
+                        .iter()
+                        .step_by(-step as usize)
+                        .cloned()
+                        .collect())
+                }
+            }
+        } else {
+            Err(vm.new_type_error(format!("Expected a slice, got {:?}", slice)))
+        }
+    }
+}
+
+pub fn get_item(
+    vm: &mut VirtualMachine,
+    sequence: &PyObjectRef,
+    elements: &Vec<PyObjectRef>,
+    subscript: PyObjectRef,
+) -> PyResult {
+    match &subscript.borrow().kind {
+        PyObjectKind::Integer { value } => {
+            let pos_index = elements.get_pos(value.to_i32().unwrap());
+            if pos_index < elements.len() {
+                Ok(elements[pos_index].clone())
+            } else {
+                Err(vm.new_index_error("Index out of bounds!".to_string()))
+            }
+        }
+        PyObjectKind::Slice {
+            start: _,
+            stop: _,
+            step: _,
+        } => Ok(vm
+            .ctx
+            .new_list(elements.get_slice_items(vm, &subscript)?)),
+        _ => Err(vm.new_type_error(format!(
+            "TypeError: indexing type {:?} with index {:?} is not supported (yet?)",
+            sequence, subscript
+        ))),
+    }
+}
+
+pub fn seq_cmp(
+    vm: &mut VirtualMachine,
+    zelf: &Vec<PyObjectRef>,
+    other: &Vec<PyObjectRef>,
+) -> PyResult<std::cmp::Ordering> {
+    for (a, b) in Iterator::zip(zelf.iter(), other.iter()) {
+        let eq = vm.call_method(a, "__eq__", vec![b.clone()])?;
+        if !super::objbool::get_value(&eq) {
+            let lt = vm.call_method(a, "__lt__", vec![b.clone()])?;
+            return Ok(if super::objbool::get_value(&lt) {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Greater
+            });
+        }
+    }
+    Ok(zelf.len().cmp(&other.len()))
+}
+
+pub fn seq_equal(
+    vm: &mut VirtualMachine,
+    zelf: Vec<PyObjectRef>,
+    other: Vec<PyObjectRef>,
+) -> PyResult<bool> {
+    if zelf.len() == other.len() {
+        for (a, b) in Iterator::zip(zelf.iter(), other.iter()) {
+            let eq = vm.call_method(a, "__eq__", vec![b.clone()])?;
+            let value = super::objbool::get_value(&eq);
+            if !value {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}